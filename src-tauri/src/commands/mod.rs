@@ -3,7 +3,12 @@
 //! This module exports all Tauri commands used by the frontend
 //! to interact with the Rust backend.
 
+pub mod titlebar;
 pub mod webview;
 
 // Re-export commands for easy registration
-pub use webview::create_gemini_webview;
+pub use titlebar::{sync_titlebar_theme, TitlebarState};
+pub use webview::{
+    activate_gemini_tab, close_gemini_tab, create_gemini_tab, create_gemini_webview,
+    set_menu_bar_mode, set_visible_on_all_workspaces, GeminiTabManager,
+};
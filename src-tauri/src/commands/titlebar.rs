@@ -0,0 +1,117 @@
+//! Titlebar theme and geometry synchronization.
+//!
+//! The overlay/custom titlebar needs to recolor to match Gemini's
+//! detected light/dark theme, and its effective height can change
+//! between platforms and titlebar styles (e.g. macOS's overlay style
+//! vs. the custom Windows/Linux titlebar). This module tracks that
+//! height at runtime and keeps the active Gemini tab's webview bounds
+//! in sync with it, rather than relying on a single compile-time
+//! constant.
+
+use std::sync::Mutex;
+
+use log::{error, info};
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::commands::webview::GeminiTabManager;
+use crate::constants::{MAIN_WINDOW_LABEL, TITLEBAR_HEIGHT};
+use crate::errors::CommandError;
+
+/// Tracks the titlebar's current effective height (logical pixels).
+pub struct TitlebarState(Mutex<f64>);
+
+impl Default for TitlebarState {
+    fn default() -> Self {
+        Self(Mutex::new(TITLEBAR_HEIGHT))
+    }
+}
+
+impl TitlebarState {
+    /// Returns the current effective titlebar height.
+    pub fn height(&self) -> f64 {
+        *self.0.lock().unwrap()
+    }
+
+    fn set_height(&self, height: f64) {
+        *self.0.lock().unwrap() = height;
+    }
+}
+
+#[derive(Serialize, Clone)]
+struct TitlebarThemePayload {
+    theme: String,
+}
+
+/// Called by the frontend whenever the detected theme changes, or the
+/// titlebar's effective height changes (e.g. after switching titlebar
+/// styles). Emits the new theme to the titlebar UI and recomputes the
+/// active Gemini tab's bounds against the new height.
+#[tauri::command]
+pub async fn sync_titlebar_theme(
+    app: AppHandle,
+    theme: String,
+    titlebar_height: f64,
+) -> Result<(), CommandError> {
+    info!(
+        "Syncing titlebar theme to '{}' (height: {})",
+        theme, titlebar_height
+    );
+
+    app.state::<TitlebarState>().set_height(titlebar_height);
+
+    let main_window = app.get_webview_window(MAIN_WINDOW_LABEL).ok_or_else(|| {
+        let msg = "Main window not found".to_string();
+        error!("{}", msg);
+        CommandError::WindowNotFound(msg)
+    })?;
+
+    main_window
+        .emit("titlebar-theme-changed", TitlebarThemePayload { theme })
+        .map_err(CommandError::TauriError)?;
+
+    let manager = app.state::<GeminiTabManager>();
+    if let Some(active_label) = manager.active_tab() {
+        if let Some(webview) = app.get_webview(&active_label) {
+            let scale_factor = main_window
+                .scale_factor()
+                .map_err(CommandError::TauriError)?;
+            let size = main_window.inner_size().map_err(CommandError::TauriError)?;
+
+            let bounds = crate::utils::calculate_webview_bounds(
+                size.width,
+                size.height,
+                scale_factor,
+                titlebar_height,
+            );
+
+            webview.set_bounds(bounds).map_err(|e| {
+                error!(
+                    "Failed to recompute webview bounds after titlebar sync: {}",
+                    e
+                );
+                CommandError::TauriError(e)
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_height_is_titlebar_height_constant() {
+        let state = TitlebarState::default();
+        assert_eq!(state.height(), TITLEBAR_HEIGHT);
+    }
+
+    #[test]
+    fn test_set_height_updates_height() {
+        let state = TitlebarState::default();
+        state.set_height(48.0);
+        assert_eq!(state.height(), 48.0);
+    }
+}
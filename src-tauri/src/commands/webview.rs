@@ -1,75 +1,397 @@
 //! Webview management commands.
 //!
 //! This module contains Tauri commands for creating and managing
-//! the embedded Gemini webview.
+//! Gemini webview "tabs". What used to be a single hard-coded
+//! `gemini-webview` is now a small tab manager: callers can open
+//! several Gemini sessions as child webviews of the main window and
+//! switch which one is visible, much like browser tabs.
+//!
+//! Known deviation: the original request asked for a shared
+//! `tabbing_identifier` so tabs participate in native macOS window
+//! tabbing. Tabs here are child webviews of a single `main` window,
+//! not separate `NSWindow`s, so there is nothing for macOS's native
+//! window tabbing to attach to — `tabbing_identifier` is an `NSWindow`
+//! property, not a child-webview one, and setting it has no effect
+//! under this design. Native tabbing would require each tab to be its
+//! own window parented to `main`. That redesign was not done; this is
+//! not implemented, not merely out of scope.
+
+use std::sync::Mutex;
 
-use log::{error, info};
+use log::{error, info, warn};
 use tauri::webview::WebviewBuilder;
-use tauri::{AppHandle, Manager, WebviewUrl};
-use tauri::{PhysicalPosition, PhysicalSize, Position, Size};
+use tauri::{AppHandle, Manager, State, WebviewUrl, WebviewWindow};
+use tauri::{PhysicalPosition, PhysicalSize, Position, Rect, Size};
 
+use crate::commands::titlebar::TitlebarState;
+use crate::constants::{GEMINI_URL, GEMINI_WEBVIEW_LABEL, MAIN_WINDOW_LABEL, OPTIONS_WINDOW_LABEL};
 use crate::errors::CommandError;
+use crate::settings;
+
+/// Tracks which Gemini tabs exist and which one is currently active.
+#[derive(Default)]
+pub struct GeminiTabManager {
+    tabs: Mutex<Vec<String>>,
+    active: Mutex<Option<String>>,
+}
+
+impl GeminiTabManager {
+    /// Returns the label of the currently active tab, if any.
+    pub fn active_tab(&self) -> Option<String> {
+        self.active.lock().unwrap().clone()
+    }
+
+    fn add_tab(&self, label: &str) {
+        let mut tabs = self.tabs.lock().unwrap();
+        if !tabs.iter().any(|existing| existing == label) {
+            tabs.push(label.to_string());
+        }
+    }
+
+    fn remove_tab(&self, label: &str) {
+        self.tabs
+            .lock()
+            .unwrap()
+            .retain(|existing| existing != label);
+    }
+
+    fn tabs_snapshot(&self) -> Vec<String> {
+        self.tabs.lock().unwrap().clone()
+    }
+
+    fn has_tab(&self, label: &str) -> bool {
+        self.tabs
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|existing| existing == label)
+    }
+
+    fn set_active(&self, label: Option<String>) {
+        *self.active.lock().unwrap() = label;
+    }
+}
+
+/// Computes the bounds a visible Gemini tab should occupy: starting
+/// below the titlebar and filling the rest of the main window. Uses
+/// the titlebar's current effective height rather than a fixed
+/// constant, since it can change with titlebar style.
+fn visible_bounds(
+    app: &AppHandle,
+    main_window: &WebviewWindow,
+) -> Result<(Position, Size), CommandError> {
+    let scale_factor = main_window
+        .scale_factor()
+        .map_err(CommandError::TauriError)?;
+    let size = main_window.inner_size().map_err(CommandError::TauriError)?;
+    let titlebar_height = app.state::<TitlebarState>().height();
+
+    let bounds = crate::utils::calculate_webview_bounds(
+        size.width,
+        size.height,
+        scale_factor,
+        titlebar_height,
+    );
+    Ok((bounds.position, bounds.size))
+}
+
+/// Window labels that are already claimed by other windows and so
+/// can't double as a Gemini tab label.
+const RESERVED_TAB_LABELS: [&str; 2] = [MAIN_WINDOW_LABEL, OPTIONS_WINDOW_LABEL];
+
+/// Rejects tab labels that couldn't identify a usable tab: empty, or
+/// colliding with a window label already in use elsewhere.
+fn validate_tab_label(label: &str) -> Result<(), CommandError> {
+    if label.is_empty() {
+        return Err(CommandError::InvalidArgument(
+            "Tab label must not be empty".to_string(),
+        ));
+    }
+    if RESERVED_TAB_LABELS.contains(&label) {
+        return Err(CommandError::InvalidArgument(format!(
+            "Tab label '{}' is reserved for a window",
+            label
+        )));
+    }
+    Ok(())
+}
 
-/// Height of the custom titlebar in pixels (logical).
-const TITLEBAR_HEIGHT: f64 = 32.0;
+/// Bounds for an inactive tab: zero-sized so it doesn't render or
+/// intercept input, without tearing down and recreating the webview.
+fn hidden_bounds() -> (Position, Size) {
+    (
+        Position::Physical(PhysicalPosition { x: 0, y: 0 }),
+        Size::Physical(PhysicalSize {
+            width: 0,
+            height: 0,
+        }),
+    )
+}
 
-/// URL for the Gemini AI service.
-const GEMINI_URL: &str = "https://gemini.google.com";
+/// Creates the default Gemini webview as a child webview of the main
+/// window. This is the first tab; subsequent tabs are created with
+/// [`create_gemini_tab`].
+#[tauri::command]
+pub async fn create_gemini_webview(
+    app: AppHandle,
+    manager: State<'_, GeminiTabManager>,
+) -> Result<(), CommandError> {
+    create_tab(&app, &manager, GEMINI_WEBVIEW_LABEL).await
+}
 
-/// Creates the Gemini webview as a child webview of the main window.
+/// Creates a new Gemini tab and activates it.
 #[tauri::command]
-pub async fn create_gemini_webview(app: AppHandle) -> Result<(), CommandError> {
-    info!("Initializing Gemini webview...");
+pub async fn create_gemini_tab(
+    app: AppHandle,
+    manager: State<'_, GeminiTabManager>,
+    label: String,
+) -> Result<(), CommandError> {
+    validate_tab_label(&label)?;
+    create_tab(&app, &manager, &label).await
+}
 
-    let main_window = app.get_window("main").ok_or_else(|| {
+async fn create_tab(
+    app: &AppHandle,
+    manager: &GeminiTabManager,
+    label: &str,
+) -> Result<(), CommandError> {
+    info!("Creating Gemini tab '{}'...", label);
+
+    let main_window = app.get_webview_window(MAIN_WINDOW_LABEL).ok_or_else(|| {
         let msg = "Main window not found".to_string();
         error!("{}", msg);
         CommandError::WindowNotFound(msg)
     })?;
 
-    // Check if webview already exists
-    if app.get_webview("gemini-webview").is_some() {
-        info!("Gemini webview already exists.");
-        return Ok(());
+    if app.get_webview(label).is_some() {
+        info!("Gemini tab '{}' already exists.", label);
+        return activate_tab(app, manager, label).await;
     }
 
-    // Get window size and scale factor
-    let scale_factor = main_window
-        .scale_factor()
-        .map_err(CommandError::TauriError)?;
-    let size = main_window.inner_size().map_err(CommandError::TauriError)?;
+    let builder = WebviewBuilder::new(label, WebviewUrl::External(GEMINI_URL.parse().unwrap()));
 
-    let titlebar_height_phys = (TITLEBAR_HEIGHT * scale_factor) as u32;
+    let (position, size) = visible_bounds(app, &main_window)?;
 
-    // Calculate bounds for the child webview
-    // It should start below the titlebar and fill the rest
-    let width = size.width;
-    let height = if size.height > titlebar_height_phys {
-        size.height - titlebar_height_phys
-    } else {
-        0
-    };
+    main_window
+        .add_child(builder, position, size)
+        .map_err(|e| {
+            error!("Failed to add child webview for tab '{}': {}", label, e);
+            CommandError::TauriError(e)
+        })?;
 
-    let builder = WebviewBuilder::new(
-        "gemini-webview",
-        WebviewUrl::External(GEMINI_URL.parse().unwrap()),
-    );
+    manager.add_tab(label);
+    info!("Gemini tab '{}' created successfully.", label);
+
+    activate_tab(app, manager, label).await
+}
+
+/// Activates a Gemini tab: it is given bounds below the titlebar while
+/// every other known tab is hidden (zero-sized).
+#[tauri::command]
+pub async fn activate_gemini_tab(
+    app: AppHandle,
+    manager: State<'_, GeminiTabManager>,
+    label: String,
+) -> Result<(), CommandError> {
+    activate_tab(&app, &manager, &label).await
+}
+
+async fn activate_tab(
+    app: &AppHandle,
+    manager: &GeminiTabManager,
+    label: &str,
+) -> Result<(), CommandError> {
+    if !manager.has_tab(label) {
+        let msg = format!("Gemini tab '{}' not found", label);
+        error!("{}", msg);
+        return Err(CommandError::WindowNotFound(msg));
+    }
+
+    let main_window = app.get_webview_window(MAIN_WINDOW_LABEL).ok_or_else(|| {
+        let msg = "Main window not found".to_string();
+        error!("{}", msg);
+        CommandError::WindowNotFound(msg)
+    })?;
+
+    let (visible_position, visible_size) = visible_bounds(app, &main_window)?;
+    let (hidden_position, hidden_size) = hidden_bounds();
+
+    for tab in manager.tabs_snapshot() {
+        let Some(webview) = app.get_webview(&tab) else {
+            continue;
+        };
+
+        let bounds = if tab == label {
+            Rect {
+                position: visible_position.clone(),
+                size: visible_size.clone(),
+            }
+        } else {
+            Rect {
+                position: hidden_position.clone(),
+                size: hidden_size.clone(),
+            }
+        };
+
+        if let Err(e) = webview.set_bounds(bounds) {
+            warn!("Failed to update bounds for tab '{}': {}", tab, e);
+        }
+    }
+
+    manager.set_active(Some(label.to_string()));
+    info!("Activated Gemini tab '{}'.", label);
+    Ok(())
+}
+
+/// Closes a Gemini tab. If it was the active tab, the first remaining
+/// tab in creation order (if any) is activated in its place.
+#[tauri::command]
+pub async fn close_gemini_tab(
+    app: AppHandle,
+    manager: State<'_, GeminiTabManager>,
+    label: String,
+) -> Result<(), CommandError> {
+    validate_tab_label(&label)?;
+    info!("Closing Gemini tab '{}'...", label);
+
+    if let Some(webview) = app.get_webview(&label) {
+        webview.close().map_err(|e| {
+            error!("Failed to close webview for tab '{}': {}", label, e);
+            CommandError::TauriError(e)
+        })?;
+    }
+
+    manager.remove_tab(&label);
+
+    let was_active = manager.active_tab().as_deref() == Some(label.as_str());
+    if was_active {
+        manager.set_active(None);
+        if let Some(next) = manager.tabs_snapshot().first().cloned() {
+            activate_tab(&app, &manager, &next).await?;
+        }
+    }
+
+    info!("Gemini tab '{}' closed.", label);
+    Ok(())
+}
+
+/// Toggles whether the main window stays visible on every virtual
+/// desktop / Space, and persists the choice so it survives restarts.
+#[tauri::command]
+pub async fn set_visible_on_all_workspaces(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), CommandError> {
+    info!("Setting visible-on-all-workspaces to {}", enabled);
+
+    let main_window = app.get_webview_window(MAIN_WINDOW_LABEL).ok_or_else(|| {
+        let msg = "Main window not found".to_string();
+        error!("{}", msg);
+        CommandError::WindowNotFound(msg)
+    })?;
 
-    // Add child webview to the main window
     main_window
-        .add_child(
-            builder,
-            Position::Physical(PhysicalPosition {
-                x: 0,
-                y: titlebar_height_phys as i32,
-            }),
-            Size::Physical(PhysicalSize { width, height }),
-        )
+        .set_visible_on_all_workspaces(enabled)
         .map_err(|e| {
-            error!("Failed to add child webview: {}", e);
+            error!("Failed to set visible-on-all-workspaces: {}", e);
             CommandError::TauriError(e)
         })?;
 
-    info!("Gemini webview created successfully.");
+    let mut current = settings::load(&app);
+    current.visible_on_all_workspaces = enabled;
+    settings::save(&app, &current)?;
+
+    info!(
+        "Visible-on-all-workspaces set to {} and persisted.",
+        enabled
+    );
     Ok(())
 }
+
+/// Toggles menu bar mode (macOS Dock-less accessory app), and persists
+/// the choice so it survives restarts.
+#[tauri::command]
+pub async fn set_menu_bar_mode(app: AppHandle, enabled: bool) -> Result<(), CommandError> {
+    info!("Setting menu bar mode to {}", enabled);
+
+    crate::tray::apply_menu_bar_mode(&app, enabled);
+
+    let mut current = settings::load(&app);
+    current.menu_bar_mode = enabled;
+    settings::save(&app, &current)?;
+
+    info!("Menu bar mode set to {} and persisted.", enabled);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_tab_dedups() {
+        let manager = GeminiTabManager::default();
+        manager.add_tab("tab-1");
+        manager.add_tab("tab-1");
+        assert_eq!(manager.tabs_snapshot(), vec!["tab-1".to_string()]);
+    }
+
+    #[test]
+    fn test_remove_tab() {
+        let manager = GeminiTabManager::default();
+        manager.add_tab("tab-1");
+        manager.add_tab("tab-2");
+        manager.remove_tab("tab-1");
+        assert_eq!(manager.tabs_snapshot(), vec!["tab-2".to_string()]);
+    }
+
+    #[test]
+    fn test_has_tab() {
+        let manager = GeminiTabManager::default();
+        manager.add_tab("tab-1");
+        assert!(manager.has_tab("tab-1"));
+        assert!(!manager.has_tab("unknown-tab"));
+    }
+
+    #[test]
+    fn test_set_active_tracks_active_tab() {
+        let manager = GeminiTabManager::default();
+        assert_eq!(manager.active_tab(), None);
+
+        manager.set_active(Some("tab-1".to_string()));
+        assert_eq!(manager.active_tab(), Some("tab-1".to_string()));
+
+        manager.set_active(None);
+        assert_eq!(manager.active_tab(), None);
+    }
+
+    #[test]
+    fn test_validate_tab_label_rejects_empty() {
+        assert!(validate_tab_label("").is_err());
+    }
+
+    #[test]
+    fn test_validate_tab_label_rejects_reserved_labels() {
+        assert!(validate_tab_label(MAIN_WINDOW_LABEL).is_err());
+        assert!(validate_tab_label(OPTIONS_WINDOW_LABEL).is_err());
+    }
+
+    #[test]
+    fn test_validate_tab_label_accepts_ordinary_label() {
+        assert!(validate_tab_label("tab-1").is_ok());
+    }
+
+    #[test]
+    fn test_hidden_bounds_is_zero_sized() {
+        let (position, size) = hidden_bounds();
+        let Position::Physical(position) = position else {
+            panic!("expected a physical position");
+        };
+        let Size::Physical(size) = size else {
+            panic!("expected a physical size");
+        };
+
+        assert_eq!((position.x, position.y), (0, 0));
+        assert_eq!((size.width, size.height), (0, 0));
+    }
+}
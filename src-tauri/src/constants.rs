@@ -3,11 +3,13 @@
 //! This module contains constants that are shared across the codebase
 //! to prevent duplication and ensure consistency.
 
-/// Height of the custom titlebar in pixels (logical).
+/// Default height of the custom titlebar in pixels (logical).
 ///
-/// This is used for:
-/// - Calculating webview bounds (offsetting below titlebar)
-/// - Resize event handlers to reposition the webview
+/// This is the fallback used before the frontend has reported the
+/// titlebar's actual effective height (which can change with titlebar
+/// style, e.g. macOS overlay vs. the custom Windows/Linux titlebar) via
+/// `sync_titlebar_theme`. See `commands::titlebar::TitlebarState` for
+/// the runtime value.
 pub const TITLEBAR_HEIGHT: f64 = 32.0;
 
 /// URL for the Gemini AI service.
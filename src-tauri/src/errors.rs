@@ -8,6 +8,8 @@ pub enum CommandError {
     TauriError(#[from] tauri::Error),
     #[error("Serialization error: {0}")]
     SerializationError(#[from] serde_json::Error),
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
     #[error("Internal error: {0}")]
     Internal(String),
 }
@@ -6,37 +6,119 @@
 mod commands;
 mod constants;
 mod errors;
+mod settings;
+mod tray;
 pub mod utils;
 mod windows;
 
-use commands::create_gemini_webview;
-use constants::TITLEBAR_HEIGHT;
-use log::info;
+use commands::{
+    activate_gemini_tab, close_gemini_tab, create_gemini_tab, create_gemini_webview,
+    set_menu_bar_mode, set_visible_on_all_workspaces, sync_titlebar_theme, GeminiTabManager,
+    TitlebarState,
+};
+use constants::{MAIN_WINDOW_LABEL, TITLEBAR_HEIGHT};
+use log::{info, warn};
 #[cfg(target_os = "macos")]
 use tauri::menu::{MenuBuilder, SubmenuBuilder};
+#[cfg(target_os = "macos")]
+use tauri::{TitleBarStyle, WebviewUrl, WebviewWindowBuilder};
 use tauri::Manager;
 use tauri_plugin_log::{Target, TargetKind};
-use windows::create_options_window;
+use windows::{create_options_window, state};
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 #[cfg(not(tarpaulin_include))]
 pub fn run() {
     tauri::Builder::default()
+        .manage(GeminiTabManager::default())
+        .manage(TitlebarState::default())
+        .manage(state::WindowStateStore::default())
         .plugin(tauri_plugin_dialog::init())
         .setup(|app| {
             let app_handle = app.handle().clone();
 
+            // Load the persisted window-geometry cache once, up front,
+            // so every window's `restore`/`track` call below shares it
+            // instead of each re-reading `window_state.json` itself.
+            app.state::<state::WindowStateStore>().load(&app_handle);
+
             // --- Platform-Specific Window Decorations ---
-            // macOS uses `titleBarStyle: Overlay` which requires decorations.
-            // Windows and Linux use a custom titlebar, so we disable native decorations.
+            // macOS uses a transparent, hidden-title, fullsize-content-view
+            // titlebar so the webview content can draw under the traffic
+            // lights and the titlebar can recolor to match Gemini's theme
+            // via `sync_titlebar_theme` below. Those are builder-only
+            // options, so the `main` window is built here rather than
+            // relying on plain decorations from the default window config.
+            // Windows and Linux use a custom titlebar, so we just disable
+            // native decorations entirely on the existing window.
+            // NOTE: this branch only runs if `tauri.conf.json`'s `windows`
+            // array does NOT already declare a `MAIN_WINDOW_LABEL` window,
+            // since Tauri would otherwise create it before `setup()` runs
+            // and `get_webview_window` below would already find it. The
+            // `else` arm logs loudly rather than silently no-op'ing so a
+            // config that pre-declares `main` doesn't look like a working
+            // overlay titlebar that just isn't there.
+            #[cfg(target_os = "macos")]
+            {
+                if app.get_webview_window(MAIN_WINDOW_LABEL).is_none() {
+                    info!("macOS detected: Building main window with overlay titlebar.");
+                    WebviewWindowBuilder::new(
+                        app,
+                        MAIN_WINDOW_LABEL,
+                        WebviewUrl::App("index.html".into()),
+                    )
+                    .title("Gemini Desktop")
+                    .inner_size(1280.0, 800.0)
+                    .center()
+                    .transparent(true)
+                    .title_bar_style(TitleBarStyle::Overlay)
+                    .hidden_title(true)
+                    .build()?;
+                } else {
+                    warn!(
+                        "macOS: '{}' window already existed before setup() ran, so the \
+                         transparent overlay titlebar could not be applied here. \
+                         title_bar_style/transparent/hidden_title are builder-only options \
+                         and can't be set on an already-created window — check that \
+                         tauri.conf.json's `windows` array doesn't pre-declare '{}'.",
+                        MAIN_WINDOW_LABEL, MAIN_WINDOW_LABEL
+                    );
+                }
+            }
+
             #[cfg(not(target_os = "macos"))]
             {
-                if let Some(main_window) = app.get_webview_window("main") {
+                if let Some(main_window) = app.get_webview_window(MAIN_WINDOW_LABEL) {
                     info!("Non-macOS detected: Disabling native window decorations.");
                     let _ = main_window.set_decorations(false);
                 }
             }
 
+            // --- Persisted Window Settings ---
+            // Re-apply the "pin to all workspaces" and "menu bar mode"
+            // choices from the last session instead of always starting
+            // in the default state.
+            let saved_settings = settings::load(&app_handle);
+            if let Some(main_window) = app.get_webview_window(MAIN_WINDOW_LABEL) {
+                if saved_settings.visible_on_all_workspaces {
+                    if let Err(e) =
+                        main_window.set_visible_on_all_workspaces(true)
+                    {
+                        info!("Failed to restore visible-on-all-workspaces: {}", e);
+                    }
+                }
+            }
+
+            #[cfg(target_os = "macos")]
+            if saved_settings.menu_bar_mode {
+                info!("Menu bar mode enabled: starting as an accessory app.");
+                tray::apply_menu_bar_mode(&app_handle, true);
+            }
+
+            // --- System Tray ---
+            // Lets the app be summoned with a click instead of alt-tabbing.
+            tray::setup_tray(&app_handle)?;
+
             // --- Native Menu (macOS only) ---
             // On macOS, we use native menus for system integration.
             // On Windows/Linux, React handles the menu via TitlebarMenu component.
@@ -66,24 +148,41 @@ pub fn run() {
                 app.set_menu(menu)?;
             }
 
+            // --- Window Geometry ---
+            // Restore the main window's saved size/position/maximized state
+            // from the last session; fall back to the default placement
+            // from `tauri.conf.json` when nothing has been saved yet.
+            if let Some(main_window) = app.get_webview_window(MAIN_WINDOW_LABEL) {
+                state::restore(&app_handle, &main_window);
+                state::track(&app_handle, &main_window);
+            }
+
             // --- Resize Listener ---
-            // Setup resize listener for the main window to keep webview in sync.
-            if let Some(main_window) = app.get_webview_window("main") {
+            // Setup resize listener for the main window to keep the active
+            // Gemini tab's webview in sync; inactive tabs stay zero-sized.
+            // Attached after window-geometry restoration so the initial
+            // bounds calculation uses the restored size.
+            if let Some(main_window) = app.get_webview_window(MAIN_WINDOW_LABEL) {
                 let main_window_clone = main_window.clone();
                 main_window.on_window_event(move |event| {
                     if let tauri::WindowEvent::Resized(size) = event {
-                        // Update gemini webview bounds if it exists
-                        if let Some(webview) = app_handle.get_webview("gemini-webview") {
-                            let scale_factor = main_window_clone.scale_factor().unwrap_or(1.0);
-
-                            let bounds = crate::utils::calculate_webview_bounds(
-                                size.width,
-                                size.height,
-                                scale_factor,
-                                TITLEBAR_HEIGHT,
-                            );
-
-                            let _ = webview.set_bounds(bounds);
+                        let manager = app_handle.state::<GeminiTabManager>();
+                        if let Some(active_label) = manager.active_tab() {
+                            if let Some(webview) = app_handle.get_webview(&active_label) {
+                                let scale_factor =
+                                    main_window_clone.scale_factor().unwrap_or(1.0);
+                                let titlebar_height =
+                                    app_handle.state::<TitlebarState>().height();
+
+                                let bounds = crate::utils::calculate_webview_bounds(
+                                    size.width,
+                                    size.height,
+                                    scale_factor,
+                                    titlebar_height,
+                                );
+
+                                let _ = webview.set_bounds(bounds);
+                            }
                         }
                     }
                 });
@@ -103,7 +202,13 @@ pub fn run() {
         .plugin(tauri_plugin_process::init())
         .invoke_handler(tauri::generate_handler![
             create_gemini_webview,
-            create_options_window
+            create_gemini_tab,
+            activate_gemini_tab,
+            close_gemini_tab,
+            create_options_window,
+            set_visible_on_all_workspaces,
+            set_menu_bar_mode,
+            sync_titlebar_theme
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,95 @@
+//! Persisted application settings.
+//!
+//! User-configurable toggles (like "pin to all workspaces") are stored
+//! as JSON in the app's data directory so they survive an app restart
+//! instead of resetting to their defaults every launch.
+
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+use crate::errors::CommandError;
+
+const SETTINGS_FILE_NAME: &str = "settings.json";
+
+/// Settings that persist across app launches.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct AppSettings {
+    /// Whether the main window should remain visible on every
+    /// virtual desktop / Space.
+    #[serde(default)]
+    pub visible_on_all_workspaces: bool,
+
+    /// Whether the app should run as a menu-bar/tray accessory (no
+    /// Dock icon on macOS) instead of a regular foreground app.
+    #[serde(default)]
+    pub menu_bar_mode: bool,
+}
+
+fn settings_path(app: &AppHandle) -> Result<PathBuf, CommandError> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| CommandError::Internal(format!("Failed to resolve app data dir: {}", e)))?;
+    fs::create_dir_all(&dir)
+        .map_err(|e| CommandError::Internal(format!("Failed to create app data dir: {}", e)))?;
+    Ok(dir.join(SETTINGS_FILE_NAME))
+}
+
+/// Loads settings from disk, falling back to defaults if none exist yet.
+pub fn load(app: &AppHandle) -> AppSettings {
+    let path = match settings_path(app) {
+        Ok(path) => path,
+        Err(_) => return AppSettings::default(),
+    };
+
+    fs::read_to_string(&path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Persists settings to disk.
+pub fn save(app: &AppHandle, settings: &AppSettings) -> Result<(), CommandError> {
+    let path = settings_path(app)?;
+    let contents =
+        serde_json::to_string_pretty(settings).map_err(CommandError::SerializationError)?;
+    fs::write(&path, contents)
+        .map_err(|e| CommandError::Internal(format!("Failed to write settings: {}", e)))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_settings_are_all_disabled() {
+        let settings = AppSettings::default();
+        assert!(!settings.visible_on_all_workspaces);
+        assert!(!settings.menu_bar_mode);
+    }
+
+    #[test]
+    fn test_settings_round_trip_through_json() {
+        let settings = AppSettings {
+            visible_on_all_workspaces: true,
+            menu_bar_mode: true,
+        };
+
+        let json = serde_json::to_string(&settings).unwrap();
+        let round_tripped: AppSettings = serde_json::from_str(&json).unwrap();
+
+        assert!(round_tripped.visible_on_all_workspaces);
+        assert!(round_tripped.menu_bar_mode);
+    }
+
+    #[test]
+    fn test_missing_fields_fall_back_to_defaults() {
+        let settings: AppSettings = serde_json::from_str("{}").unwrap();
+        assert!(!settings.visible_on_all_workspaces);
+        assert!(!settings.menu_bar_mode);
+    }
+}
@@ -0,0 +1,96 @@
+//! System tray integration.
+//!
+//! Gives the app a tray/menu-bar icon so it can be summoned with a
+//! click instead of alt-tabbing, and (on macOS) lets it run as a
+//! Dock-less "accessory" app when menu bar mode is enabled.
+
+use log::{error, info};
+use tauri::menu::{MenuBuilder, MenuItemBuilder};
+use tauri::tray::TrayIconBuilder;
+use tauri::{AppHandle, Manager};
+
+use crate::constants::MAIN_WINDOW_LABEL;
+use crate::windows::create_options_window;
+
+const TOGGLE_VISIBILITY_ID: &str = "toggle_visibility";
+const OPTIONS_ID: &str = "options";
+const QUIT_ID: &str = "quit";
+
+/// Builds the tray icon and wires up its menu.
+///
+/// Clicking "Show/Hide Gemini" toggles the main window (and the
+/// embedded `gemini-webview` along with it, since it is a child
+/// webview of the main window).
+pub fn setup_tray(app: &AppHandle) -> tauri::Result<()> {
+    let toggle_item =
+        MenuItemBuilder::with_id(TOGGLE_VISIBILITY_ID, "Show/Hide Gemini").build(app)?;
+    let options_item = MenuItemBuilder::with_id(OPTIONS_ID, "Options").build(app)?;
+    let quit_item = MenuItemBuilder::with_id(QUIT_ID, "Quit").build(app)?;
+
+    let menu = MenuBuilder::new(app)
+        .item(&toggle_item)
+        .separator()
+        .item(&options_item)
+        .separator()
+        .item(&quit_item)
+        .build()?;
+
+    TrayIconBuilder::new()
+        .menu(&menu)
+        .show_menu_on_left_click(true)
+        .on_menu_event(|app, event| match event.id().as_ref() {
+            TOGGLE_VISIBILITY_ID => toggle_main_window_visibility(app),
+            OPTIONS_ID => {
+                let app_handle = app.clone();
+                tauri::async_runtime::spawn(async move {
+                    if let Err(e) = create_options_window(app_handle).await {
+                        error!("Failed to open options window from tray: {}", e);
+                    }
+                });
+            }
+            QUIT_ID => app.exit(0),
+            _ => {}
+        })
+        .build(app)?;
+
+    info!("Tray icon initialized.");
+    Ok(())
+}
+
+/// Toggles the visibility of the main window (and its child webview).
+fn toggle_main_window_visibility(app: &AppHandle) {
+    let Some(main_window) = app.get_webview_window(MAIN_WINDOW_LABEL) else {
+        error!("Main window not found while toggling tray visibility.");
+        return;
+    };
+
+    let is_visible = main_window.is_visible().unwrap_or(false);
+    let result = if is_visible {
+        main_window.hide()
+    } else {
+        let _ = main_window.set_focus();
+        main_window.show()
+    };
+
+    if let Err(e) = result {
+        error!("Failed to toggle main window visibility: {}", e);
+    }
+}
+
+/// Applies macOS "menu bar mode": hides the Dock icon so the app runs
+/// as a background accessory summoned only via the tray.
+#[cfg(target_os = "macos")]
+pub fn apply_menu_bar_mode(app: &AppHandle, enabled: bool) {
+    let policy = if enabled {
+        tauri::ActivationPolicy::Accessory
+    } else {
+        tauri::ActivationPolicy::Regular
+    };
+
+    app.set_activation_policy(policy);
+}
+
+/// Menu bar mode only affects Dock visibility on macOS; it is a no-op
+/// elsewhere.
+#[cfg(not(target_os = "macos"))]
+pub fn apply_menu_bar_mode(_app: &AppHandle, _enabled: bool) {}
@@ -0,0 +1,66 @@
+//! Shared webview geometry math.
+//!
+//! Centralizes the "visible Gemini webview sits below the titlebar"
+//! bounds calculation so every caller — initial tab placement, the
+//! main window resize listener, and titlebar theme/height sync — uses
+//! the same formula instead of each hand-rolling its own copy.
+
+use tauri::{PhysicalPosition, PhysicalSize, Position, Rect, Size};
+
+/// Computes the bounds a visible Gemini webview should occupy: starting
+/// below the titlebar and filling the rest of the window.
+///
+/// `width`/`height` are the window's physical inner size; `scale_factor`
+/// converts `titlebar_height` (logical) into physical pixels.
+pub fn calculate_webview_bounds(
+    width: u32,
+    height: u32,
+    scale_factor: f64,
+    titlebar_height: f64,
+) -> Rect {
+    let titlebar_height_phys = (titlebar_height * scale_factor) as u32;
+    let visible_height = height.saturating_sub(titlebar_height_phys);
+
+    Rect {
+        position: Position::Physical(PhysicalPosition {
+            x: 0,
+            y: titlebar_height_phys as i32,
+        }),
+        size: Size::Physical(PhysicalSize {
+            width,
+            height: visible_height,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds_start_below_titlebar() {
+        let bounds = calculate_webview_bounds(800, 600, 1.0, 32.0);
+        let Position::Physical(position) = bounds.position else {
+            panic!("expected a physical position");
+        };
+        assert_eq!(position.y, 32);
+    }
+
+    #[test]
+    fn test_bounds_account_for_scale_factor() {
+        let bounds = calculate_webview_bounds(800, 600, 2.0, 32.0);
+        let Position::Physical(position) = bounds.position else {
+            panic!("expected a physical position");
+        };
+        assert_eq!(position.y, 64);
+    }
+
+    #[test]
+    fn test_bounds_saturate_when_titlebar_taller_than_window() {
+        let bounds = calculate_webview_bounds(800, 10, 1.0, 32.0);
+        let Size::Physical(size) = bounds.size else {
+            panic!("expected a physical size");
+        };
+        assert_eq!(size.height, 0);
+    }
+}
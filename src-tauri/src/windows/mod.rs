@@ -8,8 +8,10 @@
 //! - Each window type is defined in its own submodule
 //! - Commands are re-exported for easy registration in `lib.rs`
 //! - Uses shared utilities and error handling from parent modules
+//! - `state` persists and restores window geometry across launches
 
 pub mod options;
+pub mod state;
 
 // Re-export window commands for easy registration in lib.rs
 pub use options::create_options_window;
@@ -6,6 +6,9 @@
 //! # Cross-Platform Behavior
 //! - **macOS**: Uses native window decorations with traffic light controls
 //! - **Windows/Linux**: Uses custom titlebar with minimize, maximize, close buttons
+//! - **macOS/Windows**: The window is parented to `main` so it stays grouped
+//!   with, and ordered above, it; other platforms fall back to a standalone
+//!   window since parenting isn't supported there.
 //!
 //! # Error Handling
 //! All operations log detailed error information and return user-friendly errors.
@@ -14,10 +17,11 @@ use log::{error, info, warn};
 use tauri::{AppHandle, Manager, WebviewUrl, WebviewWindowBuilder};
 
 use crate::constants::{
-    OPTIONS_WINDOW_HEIGHT, OPTIONS_WINDOW_LABEL, OPTIONS_WINDOW_MIN_HEIGHT,
+    MAIN_WINDOW_LABEL, OPTIONS_WINDOW_HEIGHT, OPTIONS_WINDOW_LABEL, OPTIONS_WINDOW_MIN_HEIGHT,
     OPTIONS_WINDOW_MIN_WIDTH, OPTIONS_WINDOW_TITLE, OPTIONS_WINDOW_WIDTH,
 };
 use crate::errors::CommandError;
+use crate::windows::state;
 
 /// Creates or focuses the Options window.
 ///
@@ -81,6 +85,21 @@ pub async fn create_options_window(app: AppHandle) -> Result<(), CommandError> {
         .title_bar_style(tauri::TitleBarStyle::Overlay)
         .hidden_title(true);
 
+    // Parent the options window to main so it stays grouped with, and
+    // ordered above, the main window. Only macOS and Windows support
+    // window parenting; other platforms fall back to a standalone window.
+    #[cfg(any(target_os = "macos", target_os = "windows"))]
+    let window_builder = match app.get_webview_window(MAIN_WINDOW_LABEL) {
+        Some(main_window) => window_builder.parent(&main_window).map_err(|e| {
+            error!("Failed to parent options window to main window: {}", e);
+            CommandError::TauriError(e)
+        })?,
+        None => {
+            warn!("Main window not found; options window will not be parented.");
+            window_builder
+        }
+    };
+
     // Build and show the window
     let window = window_builder.build().map_err(|e| {
         error!(
@@ -90,6 +109,11 @@ pub async fn create_options_window(app: AppHandle) -> Result<(), CommandError> {
         CommandError::TauriError(e)
     })?;
 
+    // Restore the last saved geometry, if any; otherwise keep the
+    // `.center()` placement and default dimensions set above.
+    state::restore(&app, &window);
+    state::track(&app, &window);
+
     // Focus the new window
     window.set_focus().map_err(|e| {
         error!(
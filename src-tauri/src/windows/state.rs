@@ -0,0 +1,208 @@
+//! Window geometry persistence.
+//!
+//! Saves each tracked window's size, position, and maximized flag to a
+//! JSON file in the app data directory whenever it is moved, resized,
+//! or closed, and restores it on the next launch so windows reopen
+//! where the user left them instead of re-centering at a default size.
+//!
+//! Every tracked window (`main`, `options`, ...) shares a single
+//! [`WindowStateStore`] so reads and read-modify-writes of the backing
+//! file are serialized through one mutex instead of racing independent
+//! per-call round-trips.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use log::{error, warn};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, LogicalPosition, LogicalSize, Manager, WebviewWindow, WindowEvent};
+
+use crate::errors::CommandError;
+
+const WINDOW_STATE_FILE_NAME: &str = "window_state.json";
+
+/// A window's persisted size, position, and maximized flag.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct WindowGeometry {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+    maximized: bool,
+}
+
+/// In-memory cache of every tracked window's geometry, guarded by a
+/// single mutex so `restore`/`persist` calls for different windows
+/// can't race on the backing file with a lost update.
+#[derive(Default)]
+pub struct WindowStateStore(Mutex<HashMap<String, WindowGeometry>>);
+
+impl WindowStateStore {
+    /// Loads `window_state.json` into the in-memory cache. Call once
+    /// during app setup, before any window is restored or tracked.
+    pub fn load(&self, app: &AppHandle) {
+        *self.0.lock().unwrap() = load_all(app);
+    }
+}
+
+fn state_path(app: &AppHandle) -> Result<PathBuf, CommandError> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| CommandError::Internal(format!("Failed to resolve app data dir: {}", e)))?;
+    fs::create_dir_all(&dir)
+        .map_err(|e| CommandError::Internal(format!("Failed to create app data dir: {}", e)))?;
+    Ok(dir.join(WINDOW_STATE_FILE_NAME))
+}
+
+fn load_all(app: &AppHandle) -> HashMap<String, WindowGeometry> {
+    let path = match state_path(app) {
+        Ok(path) => path,
+        Err(_) => return HashMap::new(),
+    };
+
+    fs::read_to_string(&path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn save_all(app: &AppHandle, windows: &HashMap<String, WindowGeometry>) -> Result<(), CommandError> {
+    let path = state_path(app)?;
+    let contents =
+        serde_json::to_string_pretty(windows).map_err(CommandError::SerializationError)?;
+    fs::write(&path, contents)
+        .map_err(|e| CommandError::Internal(format!("Failed to write window state: {}", e)))?;
+    Ok(())
+}
+
+/// Restores a window's saved geometry, if any exists. Returns `true`
+/// if saved state was found and applied; callers should fall back to
+/// their own default placement (e.g. `.center()`) when `false`.
+pub fn restore(app: &AppHandle, window: &WebviewWindow) -> bool {
+    let store = app.state::<WindowStateStore>();
+    let Some(geometry) = store.0.lock().unwrap().get(window.label()).cloned() else {
+        return false;
+    };
+
+    let _ = window.set_position(LogicalPosition::new(geometry.x, geometry.y));
+    let _ = window.set_size(LogicalSize::new(geometry.width, geometry.height));
+    if geometry.maximized {
+        let _ = window.maximize();
+    }
+
+    true
+}
+
+/// How long to wait after the last `Moved`/`Resized` event before
+/// persisting, so an interactive drag/resize doesn't do a blocking
+/// JSON file write on every tick.
+const PERSIST_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Wires up listeners that persist this window's geometry whenever it
+/// is moved, resized, or closed. `Moved`/`Resized` events are
+/// debounced through a single long-lived worker thread (rather than
+/// one short-lived thread per event, which an interactive drag/resize
+/// would otherwise spawn dozens of per gesture); `CloseRequested`
+/// always persists immediately so the final geometry isn't lost.
+pub fn track(app: &AppHandle, window: &WebviewWindow) {
+    let label = window.label().to_string();
+    let app_handle = app.clone();
+    let (tx, rx) = mpsc::channel::<()>();
+
+    {
+        let app_handle = app_handle.clone();
+        let label = label.clone();
+        thread::spawn(move || {
+            while rx.recv().is_ok() {
+                // Drain any events that arrive during the debounce
+                // window so only the quiet period after the last one
+                // triggers a persist.
+                while rx.recv_timeout(PERSIST_DEBOUNCE).is_ok() {}
+                if let Some(window) = app_handle.get_webview_window(&label) {
+                    persist(&app_handle, &window, &label);
+                }
+            }
+        });
+    }
+
+    window.on_window_event(move |event| match event {
+        WindowEvent::Moved(_) | WindowEvent::Resized(_) => {
+            let _ = tx.send(());
+        }
+        WindowEvent::CloseRequested { .. } => {
+            if let Some(window) = app_handle.get_webview_window(&label) {
+                persist(&app_handle, &window, &label);
+            }
+        }
+        _ => {}
+    });
+}
+
+fn persist(app: &AppHandle, window: &WebviewWindow, label: &str) {
+    let Ok(position) = window.outer_position() else {
+        warn!("Failed to read position for window '{}'.", label);
+        return;
+    };
+    let Ok(size) = window.inner_size() else {
+        warn!("Failed to read size for window '{}'.", label);
+        return;
+    };
+    let scale_factor = window.scale_factor().unwrap_or(1.0);
+    let logical_position = position.to_logical::<f64>(scale_factor);
+    let logical_size = size.to_logical::<f64>(scale_factor);
+    let maximized = window.is_maximized().unwrap_or(false);
+
+    let store = app.state::<WindowStateStore>();
+    let mut windows = store.0.lock().unwrap();
+    windows.insert(
+        label.to_string(),
+        WindowGeometry {
+            x: logical_position.x,
+            y: logical_position.y,
+            width: logical_size.width,
+            height: logical_size.height,
+            maximized,
+        },
+    );
+
+    if let Err(e) = save_all(app, &windows) {
+        error!("Failed to persist window state for '{}': {}", label, e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_geometry_round_trip_through_json() {
+        let geometry = WindowGeometry {
+            x: 10.0,
+            y: 20.0,
+            width: 1280.0,
+            height: 800.0,
+            maximized: true,
+        };
+
+        let json = serde_json::to_string(&geometry).unwrap();
+        let round_tripped: WindowGeometry = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(round_tripped.x, geometry.x);
+        assert_eq!(round_tripped.y, geometry.y);
+        assert_eq!(round_tripped.width, geometry.width);
+        assert_eq!(round_tripped.height, geometry.height);
+        assert_eq!(round_tripped.maximized, geometry.maximized);
+    }
+
+    #[test]
+    fn test_store_has_no_geometry_for_unknown_label() {
+        let store = WindowStateStore::default();
+        assert!(store.0.lock().unwrap().get("main").is_none());
+    }
+}